@@ -172,6 +172,31 @@ where
     env_with(|instance| instance.input::<T>())
 }
 
+/// The flags used to indicate special operations when returning from a
+/// contract execution.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ReturnFlags {
+    value: u32,
+}
+
+impl ReturnFlags {
+    /// Creates return flags that signal a revert to the host.
+    ///
+    /// # Note
+    ///
+    /// All storage changes performed during the current contract execution
+    /// are rolled back by the host while the encoded return value is still
+    /// delivered to the caller.
+    pub fn revert() -> Self {
+        Self { value: 0x1 }
+    }
+
+    /// Returns `true` if the revert bit is set.
+    pub fn did_revert(&self) -> bool {
+        self.value & 0x1 == 0x1
+    }
+}
+
 /// Returns the value back to the caller of the executed contract.
 ///
 /// # Note
@@ -187,6 +212,22 @@ where
     env_with(|instance| instance.output::<T, R>(return_value))
 }
 
+/// Returns the value back to the caller of the executed contract, optionally
+/// reverting all storage changes performed during the execution.
+///
+/// # Note
+///
+/// The setting of this property must be the last interaction between
+/// the executed contract and its environment.
+/// The environment access asserts this guarantee.
+pub fn output_with_flags<T, R>(flags: ReturnFlags, return_value: &R)
+where
+    T: Env,
+    R: scale::Encode,
+{
+    env_with(|instance| instance.output_with_flags::<T, R>(flags, return_value))
+}
+
 /// Returns a random hash.
 ///
 /// # Note
@@ -199,6 +240,22 @@ where
     env_with(|instance| instance.random::<T>(subject))
 }
 
+/// Returns a random hash and the block number from which the randomness
+/// material was drawn.
+///
+/// # Note
+///
+/// The subject buffer can be used to further randomize the hash. The
+/// returned block number allows callers to reason about the freshness of
+/// the randomness, e.g. to avoid reusing a subject across blocks in a
+/// commit-reveal scheme.
+pub fn random_with_block_number<T>(subject: &[u8]) -> (T::Hash, T::BlockNumber)
+where
+    T: Env,
+{
+    env_with(|instance| instance.random_with_block_number::<T>(subject))
+}
+
 /// Prints the given contents to the environmental log.
 pub fn println<T>(content: &str)
 where
@@ -220,3 +277,114 @@ where
 {
     env_with(|instance| instance.get_runtime_storage::<T, R>(key))
 }
+
+/// Appends `msg` to the debug message buffer.
+///
+/// # Note
+///
+/// The buffer is only populated during off-chain (RPC) execution and is a
+/// no-op when the contract is executed on-chain.
+pub fn debug_message<T>(msg: &str)
+where
+    T: Env,
+{
+    env_with(|instance| instance.debug_message::<T>(msg))
+}
+
+/// Calls the chain extension registered under `func_id`, encoding `input`
+/// and decoding the host's response as `O`.
+///
+/// # Errors
+///
+/// - If no chain extension is registered under `func_id`.
+/// - If the chain extension signals failure.
+pub fn call_chain_extension<T, I, O>(func_id: u32, input: &I) -> Result<O>
+where
+    T: Env,
+    I: scale::Encode,
+    O: scale::Decode,
+{
+    env_with(|instance| instance.call_chain_extension::<T, I, O>(func_id, input))
+}
+
+/// Terminates the existence of the executed contract, transferring its
+/// remaining balance to `beneficiary`, and never returns to the caller.
+///
+/// # Note
+///
+/// This removes the calling account and transfers all remaining balance to
+/// the `beneficiary`. No further code is executed after this call; it is
+/// the equivalent of a trap.
+pub fn terminate_contract<T>(beneficiary: T::AccountId) -> !
+where
+    T: Env,
+{
+    env_with(|instance| instance.terminate_contract::<T>(beneficiary))
+}
+
+/// Restores a tombstoned contract at `dest` to the state of the executed
+/// contract.
+///
+/// # Note
+///
+/// The `filtered_keys` are the storage keys that are excluded from the
+/// hash comparison against the tombstone, typically because the restoring
+/// contract itself has already written to them.
+pub fn restore_to<T>(
+    dest: T::AccountId,
+    code_hash: T::Hash,
+    rent_allowance: T::Balance,
+    filtered_keys: &[Key],
+) where
+    T: Env,
+{
+    env_with(|instance| {
+        instance.restore_to::<T>(dest, code_hash, rent_allowance, filtered_keys)
+    })
+}
+
+/// Transfers `value` to the account `dest`.
+///
+/// # Errors
+///
+/// - If the contract does not have sufficient balance to transfer.
+/// - If the transfer would have brought the contract's balance below
+///   the existential deposit.
+pub fn transfer<T>(dest: T::AccountId, value: T::Balance) -> Result<()>
+where
+    T: Env,
+{
+    env_with(|instance| instance.transfer::<T>(dest, value))
+}
+
+/// Returns the `sha2_256` hash of the given input.
+pub fn hash_sha2_256<T>(input: &[u8]) -> [u8; 32]
+where
+    T: Env,
+{
+    env_with(|instance| instance.hash_sha2_256::<T>(input))
+}
+
+/// Returns the `keccak_256` hash of the given input.
+pub fn hash_keccak_256<T>(input: &[u8]) -> [u8; 32]
+where
+    T: Env,
+{
+    env_with(|instance| instance.hash_keccak_256::<T>(input))
+}
+
+/// Returns the `blake2_256` hash of the given input.
+pub fn hash_blake2_256<T>(input: &[u8]) -> [u8; 32]
+where
+    T: Env,
+{
+    env_with(|instance| instance.hash_blake2_256::<T>(input))
+}
+
+/// Returns the `blake2_128` hash of the given input.
+pub fn hash_blake2_128<T>(input: &[u8]) -> [u8; 16]
+where
+    T: Env,
+{
+    env_with(|instance| instance.hash_blake2_128::<T>(input))
+}
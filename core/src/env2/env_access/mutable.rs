@@ -276,6 +276,34 @@ where
         env_with(|instance| instance.random::<T>(subject))
     }
 
+    /// Returns a random hash and the block number from which the
+    /// randomness material was drawn.
+    ///
+    /// # Note
+    ///
+    /// The subject buffer can be used to further randomize the hash. The
+    /// returned block number allows callers to reason about the freshness
+    /// of the randomness, e.g. to avoid reusing a subject across blocks in
+    /// a commit-reveal scheme.
+    pub fn random_with_block_number(
+        &mut self,
+        subject: &[u8],
+    ) -> (T::Hash, T::BlockNumber) {
+        env_with(|instance| instance.random_with_block_number::<T>(subject))
+    }
+
+    /// Dispatches a runtime `Call`.
+    ///
+    /// # Note
+    ///
+    /// The call is dispatched with the executing contract's account as
+    /// origin. Dispatch is fire-and-forget: the contract only observes
+    /// whether the dispatch itself succeeded or failed, not the effects
+    /// of the call, which are applied by the runtime.
+    pub fn invoke_runtime(&mut self, call: &T::Call) -> Result<()> {
+        env_with(|instance| instance.invoke_runtime::<T>(call))
+    }
+
     /// Prints the given contents to the environmental log.
     pub fn println(&mut self, content: &str) {
         env_with(|instance| instance.println::<T>(content))
@@ -293,4 +321,71 @@ where
     {
         env_with(|instance| instance.get_runtime_storage::<T, R>(key))
     }
+
+    /// Terminates the existence of the executed contract, transferring its
+    /// remaining balance to `beneficiary`, and never returns to the caller.
+    ///
+    /// # Note
+    ///
+    /// This removes the calling account and transfers all remaining
+    /// balance to the `beneficiary`. No further code is executed after
+    /// this call; it is the equivalent of a trap.
+    pub fn terminate_contract(&mut self, beneficiary: T::AccountId) -> ! {
+        env_with(|instance| instance.terminate_contract::<T>(beneficiary))
+    }
+
+    /// Restores a tombstoned contract at `dest` to the state of the
+    /// executing contract.
+    ///
+    /// # Note
+    ///
+    /// The `filtered_keys` are the storage keys that are excluded from the
+    /// hash comparison against the tombstone, typically because the
+    /// restoring contract itself has already written to them.
+    pub fn restore_contract(
+        &mut self,
+        dest: T::AccountId,
+        code_hash: T::Hash,
+        rent_allowance: T::Balance,
+        filtered_keys: &[Key],
+    ) {
+        env_with(|instance| {
+            instance.restore_to::<T>(dest, code_hash, rent_allowance, filtered_keys)
+        })
+    }
+
+    /// Transfers `value` to the account `destination`.
+    ///
+    /// # Errors
+    ///
+    /// - If the contract does not have sufficient balance to transfer.
+    /// - If the transfer would have brought the contract's balance below
+    ///   the existential deposit.
+    pub fn transfer(
+        &mut self,
+        destination: T::AccountId,
+        value: T::Balance,
+    ) -> Result<()> {
+        env_with(|instance| instance.transfer::<T>(destination, value))
+    }
+
+    /// Returns the `sha2_256` hash of the given input.
+    pub fn hash_sha2_256(&mut self, input: &[u8]) -> [u8; 32] {
+        env_with(|instance| instance.hash_sha2_256::<T>(input))
+    }
+
+    /// Returns the `keccak_256` hash of the given input.
+    pub fn hash_keccak_256(&mut self, input: &[u8]) -> [u8; 32] {
+        env_with(|instance| instance.hash_keccak_256::<T>(input))
+    }
+
+    /// Returns the `blake2_256` hash of the given input.
+    pub fn hash_blake2_256(&mut self, input: &[u8]) -> [u8; 32] {
+        env_with(|instance| instance.hash_blake2_256::<T>(input))
+    }
+
+    /// Returns the `blake2_128` hash of the given input.
+    pub fn hash_blake2_128(&mut self, input: &[u8]) -> [u8; 16] {
+        env_with(|instance| instance.hash_blake2_128::<T>(input))
+    }
 }
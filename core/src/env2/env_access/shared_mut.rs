@@ -14,6 +14,7 @@
 
 use crate::{
     env2::{
+        api::ReturnFlags,
         call::{
             CallData,
             CallParams,
@@ -265,6 +266,23 @@ impl EnvInstance {
     /// the executed contract and its environment.
     /// The environment access asserts this guarantee.
     pub fn output<T, R>(&mut self, return_value: &R)
+    where
+        T: Env,
+        R: scale::Encode,
+    {
+        self.output_with_flags::<T, R>(ReturnFlags::default(), return_value)
+    }
+
+    /// Returns the value back to the caller of the executed contract,
+    /// optionally reverting all storage changes performed during the
+    /// execution.
+    ///
+    /// # Note
+    ///
+    /// The setting of this property must be the last interaction between
+    /// the executed contract and its environment.
+    /// The environment access asserts this guarantee.
+    pub fn output_with_flags<T, R>(&mut self, flags: ReturnFlags, return_value: &R)
     where
         T: Env,
         R: scale::Encode,
@@ -272,7 +290,7 @@ impl EnvInstance {
         self.assert_not_yet_returned();
         self.set_has_interacted();
         self.has_returned_value = true;
-        <T as Env>::output(&mut self.buffer, &return_value);
+        <T as Env>::output(&mut self.buffer, flags, &return_value);
     }
 
     /// Returns a random hash.
@@ -289,6 +307,44 @@ impl EnvInstance {
         <T as Env>::random(&mut self.buffer, subject)
     }
 
+    /// Dispatches a runtime `Call`.
+    ///
+    /// # Note
+    ///
+    /// The call is dispatched with the executing contract's account as
+    /// origin. Dispatch is fire-and-forget: the contract only observes
+    /// whether the dispatch itself succeeded or failed, not the effects
+    /// of the call, which are applied by the runtime.
+    pub fn invoke_runtime<T>(&mut self, call: &T::Call) -> Result<()>
+    where
+        T: Env,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        <T as Env>::invoke_runtime(&mut self.buffer, call)
+    }
+
+    /// Returns a random hash and the block number from which the randomness
+    /// material was drawn.
+    ///
+    /// # Note
+    ///
+    /// The subject buffer can be used to further randomize the hash. The
+    /// returned block number allows callers to reason about the freshness
+    /// of the randomness, e.g. to avoid reusing a subject across blocks in
+    /// a commit-reveal scheme.
+    pub fn random_with_block_number<T>(
+        &mut self,
+        subject: &[u8],
+    ) -> (T::Hash, T::BlockNumber)
+    where
+        T: Env,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        <T as Env>::random_with_block_number(&mut self.buffer, subject)
+    }
+
     /// Prints the given contents to the environmental log.
     pub fn println<T>(&mut self, content: &str)
     where
@@ -310,4 +366,133 @@ impl EnvInstance {
     {
         T::get_runtime_storage(&mut self.buffer, key)
     }
+
+    /// Appends `msg` to the debug message buffer.
+    ///
+    /// # Note
+    ///
+    /// The buffer is only populated during off-chain (RPC) execution and
+    /// is a no-op when the contract is executed on-chain.
+    pub fn debug_message<T>(&mut self, msg: &str)
+    where
+        T: Env,
+    {
+        <T as Env>::debug_message(msg)
+    }
+
+    /// Calls the chain extension registered under `func_id`, encoding
+    /// `input` and decoding the host's response as `O`.
+    ///
+    /// # Errors
+    ///
+    /// - If no chain extension is registered under `func_id`.
+    /// - If the chain extension signals failure.
+    pub fn call_chain_extension<T, I, O>(
+        &mut self,
+        func_id: u32,
+        input: &I,
+    ) -> Result<O>
+    where
+        T: Env,
+        I: scale::Encode,
+        O: scale::Decode,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        <T as Env>::call_chain_extension(&mut self.buffer, func_id, input)
+    }
+
+    /// Terminates the existence of the executed contract, transferring its
+    /// remaining balance to `beneficiary`, and never returns to the caller.
+    ///
+    /// # Note
+    ///
+    /// This removes the calling account and transfers all remaining
+    /// balance to the `beneficiary`. No further code is executed after
+    /// this call; it is the equivalent of a trap.
+    pub fn terminate_contract<T>(&mut self, beneficiary: T::AccountId) -> !
+    where
+        T: Env,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        self.has_returned_value = true;
+        <T as Env>::terminate(&mut self.buffer, beneficiary)
+    }
+
+    /// Restores a tombstoned contract at `dest` to the state of the
+    /// executing contract.
+    ///
+    /// # Note
+    ///
+    /// The `filtered_keys` are the storage keys that are excluded from the
+    /// hash comparison against the tombstone, typically because the
+    /// restoring contract itself has already written to them.
+    pub fn restore_to<T>(
+        &mut self,
+        dest: T::AccountId,
+        code_hash: T::Hash,
+        rent_allowance: T::Balance,
+        filtered_keys: &[Key],
+    ) where
+        T: Env,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        <T as Env>::restore_to(
+            &mut self.buffer,
+            dest,
+            code_hash,
+            rent_allowance,
+            filtered_keys,
+        )
+    }
+
+    /// Transfers `value` to the account `dest`.
+    ///
+    /// # Errors
+    ///
+    /// - If the contract does not have sufficient balance to transfer.
+    /// - If the transfer would have brought the contract's balance below
+    ///   the existential deposit.
+    pub fn transfer<T>(&mut self, dest: T::AccountId, value: T::Balance) -> Result<()>
+    where
+        T: Env,
+    {
+        self.assert_not_yet_returned();
+        self.set_has_interacted();
+        <T as Env>::transfer(&mut self.buffer, dest, value)
+    }
+
+    /// Returns the `sha2_256` hash of the given input.
+    pub fn hash_sha2_256<T>(&mut self, input: &[u8]) -> [u8; 32]
+    where
+        T: Env,
+    {
+        <T as Env>::hash_sha2_256(&mut self.buffer, input)
+    }
+
+    /// Returns the `keccak_256` hash of the given input.
+    pub fn hash_keccak_256<T>(&mut self, input: &[u8]) -> [u8; 32]
+    where
+        T: Env,
+    {
+        <T as Env>::hash_keccak_256(&mut self.buffer, input)
+    }
+
+    /// Returns the `blake2_256` hash of the given input.
+    pub fn hash_blake2_256<T>(&mut self, input: &[u8]) -> [u8; 32]
+    where
+        T: Env,
+    {
+        <T as Env>::hash_blake2_256(&mut self.buffer, input)
+    }
+
+    /// Returns the `blake2_128` hash of the given input.
+    pub fn hash_blake2_128<T>(&mut self, input: &[u8]) -> [u8; 16]
+    where
+        T: Env,
+    {
+        <T as Env>::hash_blake2_128(&mut self.buffer, input)
+    }
 }